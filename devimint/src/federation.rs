@@ -48,7 +48,7 @@ impl Federation {
             DynClientModuleGen::from(MintClientGen),
             DynClientModuleGen::from(LightningClientGen),
         ]);
-        let client = UserClient::new(cfg, decoders, module_gens, db, Default::default()).await;
+        let client = UserClient::new(cfg, decoders, module_gens, db, Default::default()).await?;
         Ok(Self {
             members,
             bitcoind,
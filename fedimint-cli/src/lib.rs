@@ -397,14 +397,15 @@ impl Opts {
         let decoders = self.load_decoders(&cfg, module_gens);
         let db = self.load_db(&decoders)?;
 
-        Ok(Client::new(
+        Client::new(
             cfg.clone(),
             decoders,
             module_gens.clone(),
             db,
             Default::default(),
         )
-        .await)
+        .await
+        .map_err_cli_general()
     }
 
     async fn build_client_ng(
@@ -718,7 +719,8 @@ impl FedimintCli {
                     .build_client(&self.module_gens)
                     .await?
                     .get_new_pegin_address(rng)
-                    .await;
+                    .await
+                    .map_err_cli_general()?;
                 Ok(CliOutput::PegInAddress {
                     address: (peg_in_address),
                 })
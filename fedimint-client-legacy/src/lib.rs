@@ -83,7 +83,10 @@ use crate::ln::incoming::ConfirmedInvoice;
 use crate::ln::outgoing::OutgoingContractAccount;
 use crate::ln::{LnClient, LnClientError};
 use crate::mint::db::{NoteKey, PendingNotesKeyPrefix};
-use crate::mint::{MintClient, MintClientError, SpendableNote};
+use crate::mint::{
+    Affordability, FetchRetryPolicy, IssuanceStatus, MintClient, MintClientError, MintHealth,
+    SpendInfo, SpendableNote,
+};
 use crate::modules::ln::config::LightningClientConfig;
 use crate::modules::ln::contracts::incoming::{IncomingContract, IncomingContractOffer};
 use crate::modules::ln::contracts::{
@@ -91,7 +94,7 @@ use crate::modules::ln::contracts::{
 };
 use crate::modules::ln::{ContractOutput, LightningGateway, LightningOutput};
 use crate::modules::mint::config::MintClientConfig;
-use crate::modules::mint::{BlindNonce, MintOutput};
+use crate::modules::mint::{BlindNonce, MintOutput, Nonce};
 use crate::modules::wallet::config::WalletClientConfig;
 use crate::modules::wallet::{PegOut, WalletInput, WalletOutput};
 use crate::outcome::legacy::OutputOutcome;
@@ -273,7 +276,7 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
         module_gens: ClientModuleGenRegistry,
         db: Database,
         secp: Secp256k1<All>,
-    ) -> Self {
+    ) -> Result<Self> {
         let api = WsFederationApi::from_config(config.as_ref());
         Self::new_with_api(config, decoders, module_gens, db, api.into(), secp).await
     }
@@ -285,9 +288,9 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
         db: Database,
         api: DynFederationApi,
         secp: Secp256k1<All>,
-    ) -> Client<T> {
-        let root_secret = Self::get_secret(&db).await;
-        Self {
+    ) -> Result<Client<T>> {
+        let root_secret = Self::get_secret(&db).await?;
+        Ok(Self {
             config,
             context: Arc::new(ClientContext {
                 decoders,
@@ -297,12 +300,12 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
                 secp,
             }),
             root_secret,
-        }
+        })
     }
 
     /// Fetches the client secret from the database or generates a new one if
     /// none is present
-    async fn get_secret(db: &Database) -> DerivableSecret {
+    async fn get_secret(db: &Database) -> Result<DerivableSecret> {
         let mut tx = db.begin_transaction().await;
         let client_secret = tx.get_value(&ClientSecretKey).await;
         let secret = if let Some(client_secret) = client_secret {
@@ -316,10 +319,13 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
             );
             secret
         };
-        tx.commit_tx().await;
-        secret.into_root_secret()
+        tx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
+        Ok(secret.into_root_secret())
     }
 
+    #[instrument(name = "Client::peg_in", skip_all, ret, err)]
     pub async fn peg_in<R: RngCore + CryptoRng>(
         &self,
         txout_proof: TxOutProof,
@@ -341,6 +347,55 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
         self.submit_tx_with_change(tx, &mut rng).await
     }
 
+    /// Claims several peg-ins, each in its own transaction, returning the
+    /// result of every peg-in in the same order
+    ///
+    /// Failing to claim one of them doesn't prevent the others from being
+    /// claimed, and the change of every submitted transaction can be fetched
+    /// on its own.
+    pub async fn peg_in_many<R: RngCore + CryptoRng>(
+        &self,
+        peg_ins: Vec<(TxOutProof, BitcoinTransaction)>,
+        mut rng: R,
+    ) -> Vec<Result<TransactionId>> {
+        let mut results = Vec::with_capacity(peg_ins.len());
+        for (txout_proof, btc_transaction) in peg_ins {
+            results.push(self.peg_in(txout_proof, btc_transaction, &mut rng).await);
+        }
+        results
+    }
+
+    /// Checks that we can claim the peg-in in `btc_transaction` and returns the
+    /// amount it credits after the peg-in fee, without submitting it
+    ///
+    /// Fails with [`ClientError::PegInAmountTooSmall`] if the peg-in doesn't
+    /// cover the fee. Also makes sure the mint's tiers can represent the
+    /// amount. Only the database is read, the federation isn't contacted.
+    pub async fn validate_peg_in(
+        &self,
+        txout_proof: TxOutProof,
+        btc_transaction: BitcoinTransaction,
+    ) -> Result<Amount> {
+        let peg_in_error = |error| match error {
+            WalletClientError::PegInAmountTooSmall => ClientError::PegInAmountTooSmall,
+            error => ClientError::WalletClientError(error),
+        };
+        let wallet_client = self.wallet_client();
+        let (_peg_in_key, peg_in_proof) = wallet_client
+            .create_pegin_input(txout_proof, btc_transaction)
+            .await
+            .map_err(peg_in_error)?;
+
+        let amount = wallet::peg_in_amount(
+            Amount::from_sats(peg_in_proof.tx_output().value),
+            wallet_client.config.fee_consensus.peg_in_abs,
+        )
+        .map_err(peg_in_error)?;
+        self.mint_client().plan_issuance(amount).await?;
+
+        Ok(amount)
+    }
+
     /// Submits a transaction to the fed, making change using our change module
     ///
     /// TODO: For safety, if the submission fails, the DB write still occurs.
@@ -352,8 +407,10 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
         rng: R,
     ) -> Result<TransactionId> {
         let mut dbtx = self.context.db.begin_transaction().await;
-        let final_tx = tx.build(self, &mut dbtx, rng).await;
-        dbtx.commit_tx().await;
+        let final_tx = tx.build(self, &mut dbtx, rng).await?;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
         let result = self
             .context
             .api
@@ -371,8 +428,9 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
     ///
     /// On success the out point of the newly issued e-cash notes is returned.
     /// It can be used to easily poll the transaction status using
-    /// [`MintClient::fetch_notes`] until it returns `Ok(())`, indicating we
-    /// received our newly issued e-cash notes.
+    /// [`Self::fetch_notes`] until it returns `Ok` with the newly issued
+    /// e-cash notes, indicating we received them.
+    #[instrument(name = "Client::reissue", skip_all, fields(notes = notes.count_items()), ret, err)]
     pub async fn reissue<R: RngCore + CryptoRng>(
         &self,
         notes: TieredMulti<SpendableNote>,
@@ -388,7 +446,9 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
             };
             dbtx.insert_entry(&key, &note).await;
         }
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         let mut tx = TransactionBuilder::default();
         let (mut keys, input) = MintClient::ecash_input(notes)?;
@@ -404,16 +464,53 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
     /// based on the federation public key. It does not check if the nonce is
     /// unspent.
     pub async fn validate_note_signatures(&self, notes: &TieredMulti<SpendableNote>) -> Result<()> {
-        let tbs_pks = &self.mint_client().config.tbs_pks;
+        let mint_client = self.mint_client();
         notes.iter_items().try_for_each(|(amt, note)| {
-            if note.note.verify(*tbs_pks.tier(&amt)?) {
+            if mint_client.verify_note(amt, &note.note)? {
                 Ok(())
             } else {
-                Err(ClientError::InvalidSignature)
+                Err(ClientError::InvalidSignature(amt))
             }
         })
     }
 
+    /// Stores notes handed over to us by another user, returning the total
+    /// amount received
+    ///
+    /// The signatures of all notes are validated first and notes we already
+    /// hold are rejected, so either all of them are stored or none. Notes
+    /// that would overflow our balance are rejected as well. Rejecting
+    /// a duplicate discards the notes stored so far, which logs a warning
+    /// about uncommitted writes. The notes aren't reissued, so until they are
+    /// (see [`Self::reissue`]) the sender is still able to double-spend them.
+    pub async fn receive_ecash(&self, notes: TieredMulti<SpendableNote>) -> Result<Amount> {
+        let amount = notes
+            .checked_total_amount()
+            .ok_or(ClientError::AmountOverflow)?;
+        let balance = self.total_amount().await?;
+        if balance.msats.checked_add(amount.msats).is_none() {
+            return Err(ClientError::AmountOverflow);
+        }
+        self.validate_note_signatures(&notes).await?;
+
+        let mut dbtx = self.context.db.begin_transaction().await;
+        for (amount, note) in notes.iter_items() {
+            let key = NoteKey {
+                amount,
+                nonce: note.note.0,
+            };
+            if dbtx.insert_entry(&key, note).await.is_some() {
+                // dropping the transaction discards the notes inserted so far
+                return Err(ClientError::DuplicateNote(note.note.0));
+            }
+        }
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
+
+        Ok(amount)
+    }
+
     /// Pay by creating notes provided (and most probably controlled) by the
     /// recipient.
     ///
@@ -453,11 +550,17 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
     /// OutPoint in `create_tx` Payer can use the `pay_to_blind_nonces`
     /// function Allows transfer of e-cash without risk of double-spend or
     /// not having exact change
+    ///
+    /// The returned closure has to be called with the `OutPoint` to store the
+    /// issuance, failing if it can't be written to the database.
     pub async fn receive_notes(
         &self,
         amount: Amount,
-    ) -> (TieredMulti<BlindNonce>, Box<dyn Fn(OutPoint)>) {
-        self.mint_client().receive_notes(amount).await
+    ) -> Result<(
+        TieredMulti<BlindNonce>,
+        Box<dyn Fn(OutPoint) -> std::result::Result<(), MintClientError>>,
+    )> {
+        Ok(self.mint_client().receive_notes(amount).await?)
     }
 
     pub async fn new_peg_out_with_fees(
@@ -532,14 +635,16 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
     /// - this function will write to the clients DB
     ///
     /// read more on fedimints address derivation: <https://fedimint.org/Fedimint/wallet/>
-    pub async fn get_new_pegin_address<R: RngCore + CryptoRng>(&self, rng: R) -> Address {
+    pub async fn get_new_pegin_address<R: RngCore + CryptoRng>(&self, rng: R) -> Result<Address> {
         let mut dbtx = self.context.db.begin_transaction().await;
         let address = self
             .wallet_client()
             .get_new_pegin_address(&mut dbtx, rng)
             .await;
-        dbtx.commit_tx().await;
-        address
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
+        Ok(address)
     }
 
     /// Issues a spendable amount of ecash
@@ -580,23 +685,38 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
             })
             .await;
         }
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         Ok(final_notes)
     }
 
-    /// Removes spent ecash from the database
-    pub async fn remove_ecash(&self, ecash: TieredMulti<SpendableNote>) {
+    /// Removes spent ecash from the database, returning a summary of the
+    /// removed notes
+    ///
+    /// Fails without removing any notes if one of them isn't stored, which
+    /// means our view of the notes diverged from the database. The notes
+    /// removed until then are restored by discarding the transaction, which
+    /// logs a warning about uncommitted writes.
+    pub async fn remove_ecash(&self, ecash: TieredMulti<SpendableNote>) -> Result<TieredSummary> {
         let mut dbtx = self.context.db.begin_transaction().await;
 
         for (amount, note) in ecash.iter_items() {
-            dbtx.remove_entry(&NoteKey {
+            let key = NoteKey {
                 amount,
                 nonce: note.note.0,
-            })
-            .await;
+            };
+            if dbtx.remove_entry(&key).await.is_none() {
+                // dropping the transaction restores the notes removed so far
+                return Err(ClientError::UnknownNote(note.note.0));
+            }
         }
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
+
+        Ok(ecash.summary())
     }
 
     /// For tests only: Select notes of a given amount, and then remint them,
@@ -634,7 +754,9 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
             })
             .await;
         }
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         Ok(notes)
     }
@@ -643,11 +765,16 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
     /// mean having queried the federation too early. Use
     /// [`MintClientError::is_retryable`] to determine if the operation
     /// should be retried at a later time.
-    pub async fn fetch_notes<'a>(&self, outpoint: OutPoint) -> Result<()> {
+    ///
+    /// On success the newly stored notes are returned.
+    #[instrument(name = "Client::fetch_notes", skip(self), level = "debug")]
+    pub async fn fetch_notes<'a>(&self, outpoint: OutPoint) -> Result<TieredMulti<SpendableNote>> {
         let mut dbtx = self.context.db.begin_transaction().await;
-        self.mint_client().fetch_notes(&mut dbtx, outpoint).await?;
-        dbtx.commit_tx().await;
-        Ok(())
+        let notes = self.mint_client().fetch_notes(&mut dbtx, outpoint).await?;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
+        Ok(notes)
     }
 
     /// Should be called after any transaction that might have failed in order
@@ -683,7 +810,9 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
             notes_to_reissue.extend(notes);
             dbtx.remove_entry(&key).await;
         }
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         debug!(target: LOG_WALLET, notes_to_reissue = ?notes_to_reissue.summary(), total = %notes_to_reissue.total_amount());
         trace!(target: LOG_WALLET, ?notes_to_reissue, "foo");
@@ -710,15 +839,32 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
         }
     }
 
+    /// Fetches the notes of all active issuances
+    ///
+    /// Notes that could be fetched are stored even if fetching others failed,
+    /// in which case [`ClientError::UnableToFetchAllNotes`] lists the failed
+    /// out points with their errors as well as the successful ones. It's
+    /// safe to drop the returned future, see [`MintClient::fetch_all_notes`].
     pub async fn fetch_all_notes<'a>(&self) -> Result<Vec<OutPoint>> {
+        self.fetch_all_notes_with_policy(FetchRetryPolicy::default())
+            .await
+    }
+
+    /// Like [`Self::fetch_all_notes`], but retries every issuance according to
+    /// `policy`, e.g. not at all in tests
+    #[instrument(name = "Client::fetch_all_notes_with_policy", skip(self))]
+    pub async fn fetch_all_notes_with_policy(
+        &self,
+        policy: FetchRetryPolicy,
+    ) -> Result<Vec<OutPoint>> {
         let (errors, outpoints): (Vec<_>, Vec<_>) = self
             .mint_client()
-            .fetch_all_notes()
+            .fetch_all_notes_with_policy(policy)
             .await
             .into_iter()
-            .partition_map(|result| match result {
-                Ok(outpoint) => Either::Right(outpoint),
-                Err(error) => Either::Left(error.into()),
+            .partition_map(|(outpoint, result)| match result {
+                Ok(_amount) => Either::Right(outpoint),
+                Err(error) => Either::Left((outpoint, error.into())),
             });
 
         if errors.is_empty() {
@@ -728,10 +874,62 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
         }
     }
 
+    pub async fn notes_fingerprint(&self) -> bitcoin_hashes::sha256::Hash {
+        self.mint_client().notes_fingerprint().await
+    }
+
+    pub async fn smallest_note(&self) -> Option<Amount> {
+        self.mint_client().smallest_note().await
+    }
+
+    pub async fn largest_note(&self) -> Option<Amount> {
+        self.mint_client().largest_note().await
+    }
+
+    pub async fn health_check(&self, timeout: Duration) -> Vec<MintHealth> {
+        self.mint_client().health_check(timeout).await
+    }
+
+    pub async fn reindex_notes(&self) -> Result<usize> {
+        Ok(self.mint_client().reindex_notes().await?)
+    }
+
+    pub async fn verify_all_notes(&self) -> Vec<(Amount, Nonce)> {
+        self.mint_client().verify_all_notes().await
+    }
+
+    pub async fn sweep(&self) -> Result<Amount> {
+        Ok(self.mint_client().sweep().await?)
+    }
+
+    pub async fn sweep_with_policy(&self, policy: FetchRetryPolicy) -> Result<Amount> {
+        Ok(self.mint_client().sweep_with_policy(policy).await?)
+    }
+
     pub async fn summary(&self) -> TieredSummary {
         self.mint_client().summary().await
     }
 
+    pub async fn total_amount(&self) -> Result<Amount> {
+        Ok(self.mint_client().total_amount().await?)
+    }
+
+    pub async fn can_afford(&self, amount: Amount) -> Result<bool> {
+        Ok(self.mint_client().can_afford(amount).await?)
+    }
+
+    pub async fn affordability(&self, amount: Amount) -> Result<Affordability> {
+        Ok(self.mint_client().affordability(amount).await?)
+    }
+
+    pub async fn spend_info(&self, amount: Amount) -> Result<SpendInfo> {
+        Ok(self.mint_client().spend_info(amount).await?)
+    }
+
+    pub async fn plan_issuance(&self, amount: Amount) -> Result<TieredSummary> {
+        Ok(self.mint_client().plan_issuance(amount).await?)
+    }
+
     // FIXME: loading all notes on memory isn't ideal, consider changing the API
     pub async fn notes(&self) -> TieredMulti<SpendableNote> {
         self.mint_client().notes().await
@@ -741,6 +939,22 @@ impl<T: AsRef<ClientConfig> + Clone + Send> Client<T> {
         self.mint_client().list_active_issuances().await
     }
 
+    pub async fn pending_issuances(&self) -> Vec<(OutPoint, Amount)> {
+        self.mint_client().pending_issuances().await
+    }
+
+    pub async fn issuance_request(&self, outpoint: OutPoint) -> Option<NoteIssuanceRequests> {
+        self.mint_client().issuance_request(outpoint).await
+    }
+
+    pub async fn prune_empty_issuances(&self) -> Result<usize> {
+        Ok(self.mint_client().prune_empty_issuances().await?)
+    }
+
+    pub async fn issuance_status(&self, outpoint: OutPoint) -> Result<IssuanceStatus> {
+        Ok(self.mint_client().issuance_status(outpoint).await?)
+    }
+
     pub async fn fetch_epoch_history(
         &self,
         epoch: u64,
@@ -807,7 +1021,9 @@ impl Client<UserClientConfig> {
         };
         let mut dbtx = self.context.db.begin_transaction().await;
         dbtx.insert_entry(&LightningGatewayKey, &gateway).await;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
         Ok(gateway)
     }
 
@@ -834,7 +1050,9 @@ impl Client<UserClientConfig> {
             )
             .await?;
 
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         let (contract_id, amount) = match &contract {
             LightningOutput::Contract(c) => {
@@ -887,7 +1105,9 @@ impl Client<UserClientConfig> {
         dbtx.remove_entry(&OutgoingPaymentKey(contract_id))
             .await
             .ok_or(ClientError::DeleteUnknownOutgoingContract)?;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         Ok(OutPoint { txid, out_idx: 0 })
     }
@@ -1086,7 +1306,7 @@ impl Client<UserClientConfig> {
             invoice,
             keypair: payment_keypair,
         };
-        self.ln_client().save_confirmed_invoice(&confirmed).await;
+        self.ln_client().save_confirmed_invoice(&confirmed).await?;
         Ok(confirmed)
     }
 
@@ -1232,14 +1452,17 @@ impl Client<GatewayClientConfig> {
     /// Note though that extended periods of staying offline will result in loss
     /// of funds anyway if the client can not claim the respective contract
     /// in time.
-    pub async fn save_outgoing_payment(&self, contract: OutgoingContractAccount) {
+    pub async fn save_outgoing_payment(&self, contract: OutgoingContractAccount) -> Result<()> {
         let mut dbtx = self.context.db.begin_transaction().await;
         dbtx.insert_entry(
             &OutgoingContractAccountKey(contract.contract.contract_id()),
             &contract,
         )
         .await;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
+        Ok(())
     }
 
     /// Lists all previously saved transactions that have not been driven to
@@ -1264,7 +1487,9 @@ impl Client<GatewayClientConfig> {
             .remove_entry(&OutgoingContractAccountKey(contract_id))
             .await
             .ok_or(ClientError::CancelUnknownOutgoingContract)?;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         self.cancel_outgoing_contract(contract_account).await
     }
@@ -1323,7 +1548,9 @@ impl Client<GatewayClientConfig> {
             .await;
         dbtx.insert_entry(&OutgoingPaymentClaimKey(contract_id), &())
             .await;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
 
         tx.input(&mut vec![self.config.redeem_key], input);
         let txid = self.submit_tx_with_change(tx, rng).await?;
@@ -1493,7 +1720,9 @@ impl Client<GatewayClientConfig> {
         let mut dbtx = self.context.db.begin_transaction().await;
         dbtx.remove_entry(&OutgoingPaymentClaimKey(contract_id))
             .await;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(ClientError::DatabaseError)?;
         Ok(())
     }
 
@@ -1642,8 +1871,14 @@ pub enum ClientError {
     OutgoingPaymentTimeout,
     #[error("Invalid amount tier {0:?}")]
     InvalidAmountTier(Amount),
-    #[error("Invalid signature")]
-    InvalidSignature,
+    #[error("Invalid signature on note of tier {0}")]
+    InvalidSignature(Amount),
+    #[error("We already hold the note with nonce {0:?}")]
+    DuplicateNote(Nonce),
+    #[error("We don't hold the note with nonce {0:?}")]
+    UnknownNote(Nonce),
+    #[error("The total amount of the notes overflows")]
+    AmountOverflow,
     #[error("Violated fee policy")]
     ViolatedFeePolicy,
     #[error("Tried to cancel outgoing contract that we don't know about")]
@@ -1665,7 +1900,9 @@ pub enum ClientError {
     #[error("The client config cannot be verified because {0:?}")]
     ConfigVerify(ConfigVerifyError),
     #[error("Failed to fetch notes we expected to be issued {0:?}")]
-    UnableToFetchAllNotes(Vec<ClientError>, Vec<OutPoint>),
+    UnableToFetchAllNotes(Vec<(OutPoint, ClientError)>, Vec<OutPoint>),
+    #[error("Database error: {0}")]
+    DatabaseError(anyhow::Error),
 }
 
 #[derive(Debug, Error)]
@@ -261,11 +261,13 @@ impl LnClient {
             .map_err(LnClientError::ApiError)
     }
 
-    pub async fn save_confirmed_invoice(&self, invoice: &ConfirmedInvoice) {
+    pub async fn save_confirmed_invoice(&self, invoice: &ConfirmedInvoice) -> Result<()> {
         let mut dbtx = self.context.db.begin_transaction().await;
         dbtx.insert_entry(&ConfirmedInvoiceKey(invoice.contract_id()), invoice)
             .await;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(LnClientError::DatabaseError)
     }
 
     pub async fn get_confirmed_invoice(&self, contract_id: ContractId) -> Result<ConfirmedInvoice> {
@@ -323,4 +325,6 @@ pub enum LnClientError {
     WrongAccountType,
     #[error("No ConfirmedOffer found for contract ID {0}")]
     NoConfirmedInvoice(ContractId),
+    #[error("Database error: {0}")]
+    DatabaseError(anyhow::Error),
 }
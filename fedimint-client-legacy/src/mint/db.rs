@@ -14,6 +14,7 @@ pub enum DbKeyPrefix {
     PendingNotes = 0x27,
     NextECashNoteIndex = 0x2a,
     NotesPerDenomination = 0x2b,
+    MaxNotesPerIssuance = 0x2e,
 }
 
 impl std::fmt::Display for DbKeyPrefix {
@@ -87,3 +88,12 @@ impl_db_lookup!(
 pub struct NotesPerDenominationKey;
 
 impl_db_record!(key = NotesPerDenominationKey, value = u16, db_prefix = 0);
+
+#[derive(Debug, Clone, Encodable, Decodable, Serialize)]
+pub struct MaxNotesPerIssuanceKey;
+
+impl_db_record!(
+    key = MaxNotesPerIssuanceKey,
+    value = u64,
+    db_prefix = DbKeyPrefix::MaxNotesPerIssuance,
+);
@@ -1,10 +1,12 @@
 pub mod db;
 
+use std::cmp::min;
 use std::fmt;
 use std::pin::Pin;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use bitcoin_hashes::sha256;
 use db::{NoteKey, NoteKeyPrefix, OutputFinalizationKey, OutputFinalizationKeyPrefix};
 use fedimint_core::api::{GlobalFederationApi, MemberError, OutputOutcomeError};
 use fedimint_core::core::client::ClientModule;
@@ -12,32 +14,116 @@ use fedimint_core::core::Decoder;
 use fedimint_core::db::DatabaseTransaction;
 use fedimint_core::encoding::{Decodable, Encodable};
 use fedimint_core::module::registry::ModuleDecoderRegistry;
-use fedimint_core::module::{ModuleCommon, TransactionItemAmount};
+use fedimint_core::module::{ApiRequestErased, ModuleCommon, TransactionItemAmount};
 use fedimint_core::tiered::InvalidAmountTierError;
-use fedimint_core::{Amount, OutPoint, Tiered, TieredMulti, TieredSummary, TransactionId};
+use fedimint_core::{Amount, OutPoint, PeerId, Tiered, TieredMulti, TieredSummary, TransactionId};
 use fedimint_mint_client::{select_notes_from_stream, MintModuleTypes};
 use futures::executor::block_on;
-use futures::{Future, StreamExt};
+use futures::{stream, Future, Stream, StreamExt};
 use secp256k1_zkp::{KeyPair, Secp256k1, Signing};
 use serde::{Deserialize, Serialize};
 use tbs::{blind_message, unblind_signature, AggregatePublicKey, BlindedSignature, BlindingKey};
 use thiserror::Error;
-use tracing::{debug, error, trace, warn};
+use tracing::{debug, error, instrument, trace, warn};
 
-use crate::mint::db::{NextECashNoteIndexKey, NotesPerDenominationKey, PendingNotesKey};
+use crate::mint::db::{
+    MaxNotesPerIssuanceKey, NextECashNoteIndexKey, NotesPerDenominationKey, PendingNotesKey,
+};
 use crate::modules::mint::config::MintClientConfig;
 use crate::modules::mint::{
     BlindNonce, MintInput, MintOutput, MintOutputBlindSignatures, MintOutputOutcome, Nonce, Note,
 };
 use crate::transaction::legacy::{Input, Output, Transaction};
 use crate::utils::ClientContext;
-use crate::{ChildId, DerivableSecret, FuturesUnordered};
+use crate::{ChildId, DerivableSecret};
 
 pub mod backup;
 
 const MINT_E_CASH_TYPE_CHILD_ID: ChildId = ChildId(0);
 const MINT_E_CASH_BACKUP_SNAPSHOT_TYPE_CHILD_ID: ChildId = ChildId(1);
-const MINT_E_CASH_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of issuances [`MintClient::fetch_all_notes`] fetches at the
+/// same time, so a wallet with many pending issuances doesn't flood the
+/// federation with requests
+pub const MAX_CONCURRENT_NOTE_FETCHES: usize = 8;
+
+/// Default for the number of notes a single issuance may create, see
+/// [`MintClient::set_max_notes_per_issuance`]
+pub const DEFAULT_MAX_NOTES_PER_ISSUANCE: u64 = 1000;
+
+/// How [`MintClient::await_fetch_notes_with_policy`] retries fetching notes
+/// the federation has not signed yet
+///
+/// The delay between attempts starts at `initial_delay` and doubles after
+/// every retryable error, capped at `max_delay`. Once `timeout` has elapsed
+/// the last error is returned. A `timeout` of zero disables retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FetchRetryPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for FetchRetryPolicy {
+    fn default() -> Self {
+        FetchRetryPolicy {
+            initial_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(2),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Status of an issuance according to the federation, see
+/// [`MintClient::issuance_status`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IssuanceStatus {
+    /// The federation doesn't know the transaction creating the issuance
+    Unknown,
+    /// The transaction was accepted but the notes aren't signed yet
+    Pending,
+    /// The notes are signed and can be fetched
+    Ready,
+}
+
+/// Whether our notes can pay a certain amount, see
+/// [`MintClient::affordability`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Affordability {
+    /// Some of our notes add up to exactly the amount, so no change is needed
+    pub exact_change: bool,
+    /// All of our notes add up to at least the amount
+    pub total_sufficient: bool,
+}
+
+/// What spending a certain amount would reveal, see [`MintClient::spend_info`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpendInfo {
+    /// Number of notes that would be spent
+    pub note_count: usize,
+    /// Total amount of these notes, more than the spent amount if change is
+    /// needed
+    pub selected_amount: Amount,
+    /// Whether the notes have to be reissued first to make change, which links
+    /// them to the change issued to us
+    pub needs_change: bool,
+}
+
+/// Reachability of a single federation member, see
+/// [`MintClient::health_check`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MintHealth {
+    pub peer: PeerId,
+    /// Round-trip time of the status request, `None` if the peer failed to
+    /// answer it in time
+    pub rtt: Option<Duration>,
+}
+
+impl MintHealth {
+    pub fn is_reachable(&self) -> bool {
+        self.rtt.is_some()
+    }
+}
 
 /// Federation module client for the Mint module. It can both create transaction
 /// inputs and outputs of the mint type.
@@ -102,7 +188,7 @@ impl fmt::Display for NoteIndex {
 /// Keeps the data to generate [`SpendableNote`] once the
 /// mint successfully processed the transaction signing the corresponding
 /// [`BlindNonce`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, Encodable, Decodable)]
+#[derive(Copy, Clone, PartialEq, Eq, Deserialize, Serialize, Encodable, Decodable)]
 pub struct NoteIssuanceRequest {
     /// Spend key from which the note nonce (corresponding public key) is
     /// derived
@@ -136,6 +222,16 @@ impl NoteIssuanceRequest {
         }
     }
 }
+
+// Only print the nonce, the keys are secret
+impl fmt::Debug for NoteIssuanceRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NoteIssuanceRequest")
+            .field("nonce", &self.nonce())
+            .finish_non_exhaustive()
+    }
+}
+
 /// Multiple [`Note`] issuance requests
 ///
 /// Keeps all the data to generate [`SpendableNote`]s once the
@@ -184,12 +280,15 @@ impl MintClient {
 
     /// Adds the final amounts of `change` to the tx before submitting it
     /// Allows for multiple `change` outputs
+    ///
+    /// Fails if one of the spent notes isn't stored or the mint's tiers can't
+    /// represent one of the `change` amounts.
     pub async fn finalize_change(
         &self,
         tx: &mut Transaction,
         dbtx: &mut DatabaseTransaction<'_>,
         change: Vec<Amount>,
-    ) {
+    ) -> Result<()> {
         // remove the spent ecash from the DB
         let mut input_ecash: Vec<(Amount, SpendableNote)> = vec![];
         for input in &tx.inputs {
@@ -199,7 +298,10 @@ impl MintClient {
                         amount,
                         nonce: note.0,
                     };
-                    let spendable = dbtx.get_value(&key).await.expect("Missing note");
+                    let spendable = dbtx
+                        .get_value(&key)
+                        .await
+                        .ok_or(MintClientError::UnknownNote(note.0))?;
                     input_ecash.push((amount, spendable));
                     dbtx.remove_entry(&key).await;
                 }
@@ -214,7 +316,7 @@ impl MintClient {
             }
             let (issuances, nonces) = self
                 .create_ecash(amount, notes_per_denomination, dbtx)
-                .await;
+                .await?;
             let out_idx = tx.outputs.len();
             tx.outputs.push(Output::Mint(MintOutput(nonces)));
             change_outputs.push((out_idx, issuances));
@@ -238,12 +340,17 @@ impl MintClient {
             )
             .await;
         }
+
+        Ok(())
     }
 
-    pub async fn set_notes_per_denomination(&self, notes: u16) {
+    pub async fn set_notes_per_denomination(&self, notes: u16) -> Result<()> {
         let mut dbtx = self.start_dbtx().await;
         dbtx.insert_entry(&NotesPerDenominationKey, &notes).await;
-        dbtx.commit_tx().await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(MintClientError::DatabaseError)?;
+        Ok(())
     }
 
     async fn notes_per_denomination(&self, dbtx: &mut DatabaseTransaction<'_>) -> u16 {
@@ -252,21 +359,72 @@ impl MintClient {
             .unwrap_or(self.config.max_notes_per_denomination - 1)
     }
 
-    /// Generates unsigned ecash, along with the private keys that can spend it
-    async fn create_ecash(
+    /// Limits how many notes a single issuance may create, since every note
+    /// costs a key derivation and a blinding operation
+    ///
+    /// Issuances that would exceed the limit fail with
+    /// [`MintClientError::TooManyNotes`]. Defaults to
+    /// [`DEFAULT_MAX_NOTES_PER_ISSUANCE`].
+    pub async fn set_max_notes_per_issuance(&self, notes: u64) -> Result<()> {
+        let mut dbtx = self.start_dbtx().await;
+        dbtx.insert_entry(&MaxNotesPerIssuanceKey, &notes).await;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(MintClientError::DatabaseError)?;
+        Ok(())
+    }
+
+    async fn max_notes_per_issuance(&self, dbtx: &mut DatabaseTransaction<'_>) -> u64 {
+        dbtx.get_value(&MaxNotesPerIssuanceKey)
+            .await
+            .unwrap_or(DEFAULT_MAX_NOTES_PER_ISSUANCE)
+    }
+
+    /// Picks the denominations of the notes issuing `amount` creates
+    ///
+    /// Fails if `amount` is zero, since such an issuance would never yield any
+    /// notes, if the mint's tiers can't represent `amount` exactly or if more
+    /// notes than the configured maximum would be needed.
+    async fn issuance_denominations(
         &self,
         amount: Amount,
         notes_per_denomination: u16,
         dbtx: &mut DatabaseTransaction<'_>,
-    ) -> (NoteIssuanceRequests, TieredMulti<BlindNonce>) {
-        let mut amount_requests: Vec<((Amount, NoteIssuanceRequest), (Amount, BlindNonce))> =
-            Vec::new();
-        let denominations = TieredSummary::represent_amount(
+    ) -> Result<TieredSummary> {
+        if amount == Amount::ZERO {
+            return Err(MintClientError::ZeroAmountIssuance);
+        }
+        let denominations = TieredSummary::try_represent_amount(
             amount,
             &self.summary().await,
             &self.config.tbs_pks,
             notes_per_denomination,
-        );
+        )
+        .map_err(MintClientError::UnrepresentableAmount)?;
+
+        let notes = denominations.count_items() as u64;
+        let max_notes = self.max_notes_per_issuance(dbtx).await;
+        if notes > max_notes {
+            return Err(MintClientError::TooManyNotes(notes, max_notes));
+        }
+        Ok(denominations)
+    }
+
+    /// Generates unsigned ecash, along with the private keys that can spend it
+    ///
+    /// Fails if [`Self::issuance_denominations`] can't find denominations for
+    /// `amount`.
+    async fn create_ecash(
+        &self,
+        amount: Amount,
+        notes_per_denomination: u16,
+        dbtx: &mut DatabaseTransaction<'_>,
+    ) -> Result<(NoteIssuanceRequests, TieredMulti<BlindNonce>)> {
+        let mut amount_requests: Vec<((Amount, NoteIssuanceRequest), (Amount, BlindNonce))> =
+            Vec::new();
+        let denominations = self
+            .issuance_denominations(amount, notes_per_denomination, dbtx)
+            .await?;
         for (amt, num) in denominations.iter() {
             for _ in 0..num {
                 let (request, blind_nonce) =
@@ -284,7 +442,7 @@ impl MintClient {
             "Generated issuance request"
         );
 
-        (note_finalization_data, sig_req.0)
+        Ok((note_finalization_data, sig_req.0))
     }
 
     pub async fn select_input(&self, amount: Amount) -> Result<(Vec<KeyPair>, Input)> {
@@ -320,6 +478,167 @@ impl MintClient {
             .await
     }
 
+    /// Checks whether `note` carries a valid federation signature for the tier
+    /// `amount`, without checking whether it was spent already
+    pub fn verify_note(
+        &self,
+        amount: Amount,
+        note: &Note,
+    ) -> std::result::Result<bool, InvalidAmountTierError> {
+        Ok(note.verify(*self.config.tbs_pks.tier(&amount)?))
+    }
+
+    /// Returns a hash over the tiers and nonces of all spendable notes
+    ///
+    /// Clients holding the same notes get the same fingerprint, so comparing
+    /// it is a cheap way to tell whether their notes diverged.
+    pub async fn notes_fingerprint(&self) -> sha256::Hash {
+        let mut notes: Vec<(Amount, Nonce)> = self
+            .start_dbtx()
+            .await
+            .find_by_prefix(&NoteKeyPrefix)
+            .await
+            .map(|(key, _note)| (key.amount, key.nonce))
+            .collect()
+            .await;
+        // don't depend on the iteration order of the database
+        notes.sort();
+        notes.consensus_hash()
+    }
+
+    /// Returns the tier of the smallest spendable note, if we hold any
+    pub async fn smallest_note(&self) -> Option<Amount> {
+        self.start_dbtx()
+            .await
+            .find_by_prefix(&NoteKeyPrefix)
+            .await
+            // unlike the descending variant used by `largest_note`, prefix
+            // lookups don't guarantee any order, so all notes have to be seen
+            .fold(None, |smallest: Option<Amount>, (key, _note)| async move {
+                Some(smallest.map_or(key.amount, |smallest| smallest.min(key.amount)))
+            })
+            .await
+    }
+
+    /// Returns the tier of the largest spendable note, if we hold any
+    pub async fn largest_note(&self) -> Option<Amount> {
+        Box::pin(
+            self.start_dbtx()
+                .await
+                .find_by_prefix_sorted_descending(&NoteKeyPrefix)
+                .await,
+        )
+        .next()
+        .await
+        .map(|(key, _note)| key.amount)
+    }
+
+    /// Requests the status of every federation member concurrently, giving
+    /// each of them `timeout` to answer, and reports which of them did and
+    /// how long it took, ordered by peer id
+    pub async fn health_check(&self, timeout: Duration) -> Vec<MintHealth> {
+        let api = &self.context.api;
+        let params = [ApiRequestErased::default().to_json()];
+        let pings = api.all_members().iter().map(|&peer| {
+            let params = &params;
+            async move {
+                let start = Instant::now();
+                let status = api.request_raw(peer, "status", params);
+                let rtt = match fedimint_core::task::timeout(timeout, status).await {
+                    Ok(Ok(_status)) => Some(start.elapsed()),
+                    Ok(Err(e)) => {
+                        debug!(%peer, "Status request failed: {e}");
+                        None
+                    }
+                    Err(_elapsed) => {
+                        debug!(%peer, "Status request timed out");
+                        None
+                    }
+                };
+                MintHealth { peer, rtt }
+            }
+        });
+        futures::future::join_all(pings).await
+    }
+
+    /// Checks the signatures of all spendable notes without modifying them,
+    /// returning the tier and nonce of every note that fails verification or
+    /// belongs to a tier the federation doesn't know
+    pub async fn verify_all_notes(&self) -> Vec<(Amount, Nonce)> {
+        self.start_dbtx()
+            .await
+            .find_by_prefix(&NoteKeyPrefix)
+            .await
+            .filter_map(|(key, note)| async move {
+                match self.verify_note(key.amount, &note.note) {
+                    Ok(true) => None,
+                    Ok(false) | Err(_) => Some((key.amount, key.nonce)),
+                }
+            })
+            .collect()
+            .await
+    }
+
+    /// Rewrites the keys of stored notes whose nonce doesn't match the nonce
+    /// of the note itself, returning how many were corrected
+    ///
+    /// Meant as a repair tool after manual database edits. Notes don't carry
+    /// their tier, so the tier in the key is kept. Fails without changing
+    /// anything if a corrected key is already taken, instead of overwriting
+    /// the note stored under it.
+    pub async fn reindex_notes(&self) -> Result<usize> {
+        let mut dbtx = self.start_dbtx().await;
+        let mismatched: Vec<(NoteKey, SpendableNote)> = dbtx
+            .find_by_prefix(&NoteKeyPrefix)
+            .await
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .filter(|(key, note)| key.nonce != note.note.0)
+            .collect();
+
+        let mut fixes: Vec<(NoteKey, NoteKey, SpendableNote)> = Vec::new();
+        for (key, note) in mismatched {
+            let fixed_key = NoteKey {
+                amount: key.amount,
+                nonce: note.note.0,
+            };
+            let already_fixed = fixes.iter().any(|(_, fixed, _)| {
+                fixed.amount == fixed_key.amount && fixed.nonce == fixed_key.nonce
+            });
+            if already_fixed || dbtx.get_value(&fixed_key).await.is_some() {
+                return Err(MintClientError::ReindexConflict(
+                    fixed_key.amount,
+                    fixed_key.nonce,
+                ));
+            }
+            fixes.push((key, fixed_key, note));
+        }
+
+        for (key, fixed_key, note) in &fixes {
+            warn!(?key, nonce = ?note.note.0, "Fixing key of stored note");
+            dbtx.remove_entry(key).await;
+            dbtx.insert_entry(fixed_key, note).await;
+        }
+        dbtx.commit_tx_result()
+            .await
+            .map_err(MintClientError::DatabaseError)?;
+
+        Ok(fixes.len())
+    }
+
+    /// Checks whether we hold a spendable note with the given `nonce`
+    pub async fn contains_nonce(&self, nonce: &Nonce) -> bool {
+        let mut dbtx = self.start_dbtx().await;
+        let mut notes = Box::pin(dbtx.find_by_prefix(&NoteKeyPrefix).await);
+        while let Some((key, _note)) = notes.next().await {
+            if &key.nonce == nonce {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Get available spendable notes with a db transaction already opened
     pub async fn get_available_notes(
         &self,
@@ -399,6 +718,64 @@ impl MintClient {
             .await
     }
 
+    /// Returns the total value of all spendable notes without loading them
+    ///
+    /// Fails with [`MintClientError::AmountOverflow`] instead of wrapping
+    /// around if the total doesn't fit into an [`Amount`].
+    pub async fn total_amount(&self) -> Result<Amount> {
+        self.summary()
+            .await
+            .checked_total_amount()
+            .ok_or(MintClientError::AmountOverflow)
+    }
+
+    /// Checks whether the total value of our notes covers `amount`
+    pub async fn can_afford(&self, amount: Amount) -> Result<bool> {
+        Ok(self.total_amount().await? >= amount)
+    }
+
+    /// Checks whether we can pay `amount` and if so whether we can do it
+    /// without needing change, using only the note counts per tier
+    pub async fn affordability(&self, amount: Amount) -> Result<Affordability> {
+        let summary = self.summary().await;
+        let total = summary
+            .checked_total_amount()
+            .ok_or(MintClientError::AmountOverflow)?;
+        Ok(Affordability {
+            exact_change: summary.clone().take_amount(amount).is_some(),
+            total_sufficient: total >= amount,
+        })
+    }
+
+    /// Returns the denominations of the notes that issuing `amount` would
+    /// request right now, without creating any of them
+    ///
+    /// The same denomination policy as for real issuances is applied, so the
+    /// plan depends on the notes we currently hold and may change once they
+    /// do. Fails like a real issuance would if `amount` is zero, the
+    /// federation's tiers can't represent it exactly or it needs too many
+    /// notes.
+    pub async fn plan_issuance(&self, amount: Amount) -> Result<TieredSummary> {
+        let mut dbtx = self.start_dbtx().await;
+        let notes_per_denomination = self.notes_per_denomination(&mut dbtx).await;
+        self.issuance_denominations(amount, notes_per_denomination, &mut dbtx)
+            .await
+    }
+
+    /// Returns which notes spending `amount` would select, without spending
+    /// them or contacting the federation
+    pub async fn spend_info(&self, amount: Amount) -> Result<SpendInfo> {
+        let notes = self.select_notes(amount).await?;
+        let selected_amount = notes
+            .checked_total_amount()
+            .ok_or(MintClientError::AmountOverflow)?;
+        Ok(SpendInfo {
+            note_count: notes.count_items(),
+            selected_amount,
+            needs_change: selected_amount != amount,
+        })
+    }
+
     /// Select notes with total amount of *at least* `amount`. If more than
     /// requested amount of notes are returned it was because exact change
     /// couldn't be made, and the next smallest amount will be returned.
@@ -417,35 +794,62 @@ impl MintClient {
             })
     }
 
+    /// Select notes with total amount of *exactly* `amount`, using as few
+    /// notes as possible by taking the largest denominations first.
+    ///
+    /// Since tiers are powers of two taking the largest fitting note first
+    /// never misses an exact combination, see [`TieredMulti::take_amount`].
+    /// The selected notes are not removed from the database.
+    pub async fn select_exact_notes(&self, amount: Amount) -> Result<TieredMulti<SpendableNote>> {
+        self.notes()
+            .await
+            .take_amount(amount)
+            .ok_or(MintClientError::InsufficientExactChange(amount))
+    }
+
     pub async fn receive_notes(
         &self,
         amount: Amount,
-    ) -> (TieredMulti<BlindNonce>, Box<dyn Fn(OutPoint)>) {
+    ) -> Result<(TieredMulti<BlindNonce>, Box<dyn Fn(OutPoint) -> Result<()>>)> {
         let db = self.context.db.clone();
         let mut dbtx = self.context.db.begin_transaction().await;
         let notes_per_denomination = self.notes_per_denomination(&mut dbtx).await;
         let (finalization, notes) = self
             .create_ecash(amount, notes_per_denomination, &mut dbtx)
-            .await;
-        dbtx.commit_tx().await;
+            .await?;
+        dbtx.commit_tx_result()
+            .await
+            .map_err(MintClientError::DatabaseError)?;
 
-        (
+        Ok((
             notes,
             Box::new(move |out_point| {
                 let mut dbtx = block_on(db.begin_transaction());
                 block_on(dbtx.insert_new_entry(&OutputFinalizationKey(out_point), &finalization));
-                block_on(dbtx.commit_tx());
+                block_on(dbtx.commit_tx_result()).map_err(MintClientError::DatabaseError)
             }),
-        )
+        ))
     }
 
     pub async fn await_fetch_notes<'a>(
         &self,
         dbtx: &mut DatabaseTransaction<'a>,
         outpoint: &OutPoint,
+    ) -> Result<OutPoint> {
+        self.await_fetch_notes_with_policy(dbtx, outpoint, FetchRetryPolicy::default())
+            .await
+    }
+
+    /// Like [`Self::await_fetch_notes`], but retries according to `policy`
+    /// instead of the default one
+    pub async fn await_fetch_notes_with_policy<'a>(
+        &self,
+        dbtx: &mut DatabaseTransaction<'a>,
+        outpoint: &OutPoint,
+        policy: FetchRetryPolicy,
     ) -> Result<OutPoint> {
         let mut total_time = Duration::ZERO;
-        let retry_duration = Duration::from_millis(200);
+        let mut retry_delay = policy.initial_delay;
 
         loop {
             match self.fetch_notes(dbtx, *outpoint).await {
@@ -454,24 +858,27 @@ impl MintClient {
                 }
                 // TODO: make mint error more expressive (currently any HTTP error) and maybe use
                 // custom return type instead of error for retrying
-                Err(e) if e.is_retryable() && total_time < MINT_E_CASH_FETCH_TIMEOUT => {
-                    trace!("Mint returned retryable error: {:?}", e);
-                    fedimint_core::task::sleep(retry_duration).await
+                Err(e) if e.is_retryable() && total_time < policy.timeout => {
+                    trace!(?retry_delay, "Mint returned retryable error: {:?}", e);
+                    fedimint_core::task::sleep(retry_delay).await
                 }
                 Err(e) => {
                     warn!("Mint returned error: {:?}", e);
                     break Err(e);
                 }
             }
-            total_time += retry_duration;
+            total_time = total_time.saturating_add(retry_delay);
+            retry_delay = min(retry_delay.saturating_mul(2), policy.max_delay);
         }
     }
 
+    /// Fetches the notes issued at `outpoint`, stores them and returns them
+    #[instrument(skip(self, dbtx), level = "debug")]
     pub async fn fetch_notes<'a>(
         &self,
         dbtx: &mut DatabaseTransaction<'a>,
         outpoint: OutPoint,
-    ) -> Result<()> {
+    ) -> Result<TieredMulti<SpendableNote>> {
         let issuance = self
             .context
             .db
@@ -495,17 +902,16 @@ impl MintClient {
 
         let notes = issuance.finalize(bsig, &self.config.tbs_pks)?;
 
-        for (amount, note) in notes.into_iter() {
+        for (amount, note) in notes.iter_items() {
             let key = NoteKey {
                 amount,
                 nonce: note.note.0,
             };
-            let value = note;
-            dbtx.insert_new_entry(&key, &value).await;
+            dbtx.insert_new_entry(&key, note).await;
         }
         dbtx.remove_entry(&OutputFinalizationKey(outpoint)).await;
 
-        Ok(())
+        Ok(notes)
     }
 
     pub async fn list_active_issuances(&self) -> Vec<(OutPoint, NoteIssuanceRequests)> {
@@ -520,28 +926,172 @@ impl MintClient {
             .await
     }
 
-    pub async fn fetch_all_notes(&self) -> Vec<Result<OutPoint>> {
-        let active_issuances = &self.list_active_issuances().await;
-        let mut results = vec![];
+    /// Lists the out points of all active issuances together with the total
+    /// amount of notes they will issue
+    pub async fn pending_issuances(&self) -> Vec<(OutPoint, Amount)> {
+        self.list_active_issuances()
+            .await
+            .into_iter()
+            .map(|(outpoint, issuance)| (outpoint, issuance.note_amount()))
+            .collect()
+    }
+
+    /// Returns the issuance waiting for the notes issued at `outpoint`, if any
+    pub async fn issuance_request(&self, outpoint: OutPoint) -> Option<NoteIssuanceRequests> {
+        self.start_dbtx()
+            .await
+            .get_value(&OutputFinalizationKey(outpoint))
+            .await
+    }
+
+    /// Removes active issuances that don't request any notes, returning how
+    /// many were removed
+    ///
+    /// Such issuances can never yield notes, so there is no point in fetching
+    /// them.
+    pub async fn prune_empty_issuances(&self) -> Result<usize> {
+        let mut dbtx = self.start_dbtx().await;
+        let mut pruned = 0;
+        for (outpoint, issuance) in self.list_active_issuances().await {
+            if issuance.note_count() == 0 {
+                dbtx.remove_entry(&OutputFinalizationKey(outpoint)).await;
+                pruned += 1;
+            }
+        }
+        dbtx.commit_tx_result()
+            .await
+            .map_err(MintClientError::DatabaseError)?;
+        Ok(pruned)
+    }
+
+    /// Asks the federation whether the notes issued at `outpoint` are signed
+    /// yet, without finalizing or storing them
+    ///
+    /// Useful to poll before calling [`Self::fetch_notes`].
+    pub async fn issuance_status(&self, outpoint: OutPoint) -> Result<IssuanceStatus> {
+        let outcome = self
+            .context
+            .api
+            .fetch_output_outcome::<MintOutputOutcome>(outpoint, &ClientModule::decoder(self))
+            .await?;
+
+        Ok(match outcome {
+            None => IssuanceStatus::Unknown,
+            Some(MintOutputOutcome(None)) => IssuanceStatus::Pending,
+            Some(MintOutputOutcome(Some(_))) => IssuanceStatus::Ready,
+        })
+    }
+
+    /// Fetches the notes of all active issuances
+    ///
+    /// Every issuance is fetched and committed on its own, so failing to fetch
+    /// one of them doesn't prevent the notes of the others from being stored.
+    /// The result of each issuance is returned next to its out point so
+    /// failed ones can be retried later. Successful ones report the amount of
+    /// the notes they stored.
+    ///
+    /// At most [`MAX_CONCURRENT_NOTE_FETCHES`] issuances are fetched at the
+    /// same time, see [`Self::fetch_all_notes_with_concurrency`].
+    ///
+    /// Dropping the future before it completes is safe: issuances committed
+    /// so far have their notes stored and are no longer active, while the
+    /// uncommitted transactions of the others are discarded, leaving them
+    /// active to be fetched again. Discarding a transaction that already
+    /// stored some notes logs a warning about its uncommitted writes, which
+    /// is expected in this case.
+    pub async fn fetch_all_notes(&self) -> Vec<(OutPoint, Result<Amount>)> {
+        self.fetch_all_notes_with_policy(FetchRetryPolicy::default())
+            .await
+    }
+
+    /// Like [`Self::fetch_all_notes`], but retries every issuance according to
+    /// `policy` instead of the default one
+    pub async fn fetch_all_notes_with_policy(
+        &self,
+        policy: FetchRetryPolicy,
+    ) -> Vec<(OutPoint, Result<Amount>)> {
+        self.fetch_all_notes_stream(MAX_CONCURRENT_NOTE_FETCHES, policy)
+            .await
+            .collect()
+            .await
+    }
+
+    /// Fetches the notes of all active issuances and returns the total amount
+    /// of our notes afterwards
+    ///
+    /// Issuances that can't be fetched yet stay active to be retried later
+    /// and don't fail the sweep. Errors that retrying won't fix, like failing
+    /// to store the notes, are returned once all issuances were tried. If
+    /// nothing is pending the federation isn't contacted at all.
+    pub async fn sweep(&self) -> Result<Amount> {
+        self.sweep_with_policy(FetchRetryPolicy::default()).await
+    }
+
+    /// Like [`Self::sweep`], but retries every issuance according to `policy`
+    /// instead of the default one
+    pub async fn sweep_with_policy(&self, policy: FetchRetryPolicy) -> Result<Amount> {
+        for (_outpoint, result) in self.fetch_all_notes_with_policy(policy).await {
+            if let Err(e) = result {
+                if !e.is_retryable() {
+                    return Err(e);
+                }
+            }
+        }
+        self.total_amount().await
+    }
+
+    /// Like [`Self::fetch_all_notes`] but fetches at most `max_concurrency`
+    /// issuances at the same time, or one if `max_concurrency` is zero
+    #[instrument(skip(self), level = "debug")]
+    pub async fn fetch_all_notes_with_concurrency(
+        &self,
+        max_concurrency: usize,
+    ) -> Vec<(OutPoint, Result<Amount>)> {
+        self.fetch_all_notes_stream(max_concurrency, FetchRetryPolicy::default())
+            .await
+            .collect()
+            .await
+    }
+
+    /// Like [`Self::fetch_all_notes_with_concurrency`] but yields the result
+    /// of every issuance as soon as it's done, e.g. to report progress, and
+    /// retries every issuance according to `policy`
+    pub async fn fetch_all_notes_stream(
+        &self,
+        max_concurrency: usize,
+        policy: FetchRetryPolicy,
+    ) -> impl Stream<Item = (OutPoint, Result<Amount>)> + '_ {
+        let active_issuances = self.list_active_issuances().await;
 
         #[cfg(not(target_family = "wasm"))]
-        let mut futures = FuturesUnordered::<Pin<Box<dyn Future<Output = _> + Send>>>::new();
+        let mut futures = Vec::<Pin<Box<dyn Future<Output = _> + Send>>>::new();
         #[cfg(target_family = "wasm")]
-        let mut futures = FuturesUnordered::<Pin<Box<dyn Future<Output = _>>>>::new();
-        for (outpoint, _) in active_issuances {
-            futures.push(Box::pin(async {
+        let mut futures = Vec::<Pin<Box<dyn Future<Output = _>>>>::new();
+        for (outpoint, issuance) in active_issuances {
+            let amount = issuance.note_amount();
+            futures.push(Box::pin(async move {
                 let mut dbtx = self.context.db.begin_transaction().await;
-                let res = self.await_fetch_notes(&mut dbtx, outpoint).await;
-                dbtx.commit_tx().await;
-                res
+                let res = match self
+                    .await_fetch_notes_with_policy(&mut dbtx, &outpoint, policy)
+                    .await
+                {
+                    Ok(_) => dbtx
+                        .commit_tx_result()
+                        .await
+                        .map(|()| amount)
+                        .map_err(MintClientError::DatabaseError),
+                    Err(e) => Err(e),
+                };
+                match &res {
+                    Ok(amount) => debug!(?outpoint, %amount, "Fetched notes"),
+                    Err(e) => warn!(?outpoint, "Failed to fetch notes: {}", e),
+                }
+                (outpoint, res)
             }))
         }
 
-        while let Some(result) = futures.next().await {
-            results.push(result);
-        }
-
-        results
+        // a limit of zero would never fetch anything
+        stream::iter(futures).buffer_unordered(max_concurrency.max(1))
     }
 }
 
@@ -590,6 +1140,11 @@ impl NoteIssuanceRequests {
     pub fn note_amount(&self) -> Amount {
         self.notes.total_amount()
     }
+
+    /// Returns the number of notes requested per tier
+    pub fn summary(&self) -> TieredSummary {
+        self.notes.summary()
+    }
 }
 
 impl NoteIssuanceRequest {
@@ -643,6 +1198,16 @@ pub enum MintClientError {
     FinalizationError(#[from] NoteFinalizationError),
     #[error("Insufficient balance. Amount requested={0} Mint balance={1}")]
     InsufficientBalance(Amount, Amount),
+    #[error("Cannot make exact change for {0} with the available notes")]
+    InsufficientExactChange(Amount),
+    #[error("The mint's tiers can't represent the amount exactly, {0} are left over")]
+    UnrepresentableAmount(Amount),
+    #[error("Cannot issue notes for a zero amount")]
+    ZeroAmountIssuance,
+    #[error("The total amount of our notes overflows")]
+    AmountOverflow,
+    #[error("Issuing the amount needs {0} notes, more than the maximum of {1}")]
+    TooManyNotes(u64, u64),
     #[error("The transaction outcome received from the mint did not contain a result for output {0} yet")]
     OutputNotReadyYet(OutPoint),
     #[error("Output outcome error: {0}")]
@@ -653,6 +1218,12 @@ pub enum MintClientError {
     InvalidOutcomeType(OutPoint),
     #[error("One of the notes meant to be spent is unspendable")]
     ReceivedUnspendableNote,
+    #[error("We don't hold the note with nonce {0:?} meant to be spent")]
+    UnknownNote(Nonce),
+    #[error("Another note is already stored under the corrected key ({0}, {1:?})")]
+    ReindexConflict(Amount, Nonce),
+    #[error("Database error: {0}")]
+    DatabaseError(anyhow::Error),
 }
 
 impl MintClientError {
@@ -12,7 +12,9 @@ use crate::modules::ln::contracts::ContractOutcome;
 use crate::modules::ln::LightningOutputOutcome;
 use crate::outcome::legacy::OutputOutcome;
 use crate::transaction::legacy::{Input, Output, Transaction};
-use crate::{module_decode_stubs, Client, DecryptedPreimage, MintClient, MintOutputOutcome};
+use crate::{
+    module_decode_stubs, Client, DecryptedPreimage, MintClient, MintClientError, MintOutputOutcome,
+};
 
 /// Old transaction definition used by old client.
 pub mod legacy {
@@ -272,7 +274,7 @@ impl TransactionBuilder {
         client: &Client<C>,
         dbtx: &mut DatabaseTransaction<'_>,
         rng: R,
-    ) -> Transaction {
+    ) -> Result<Transaction, MintClientError> {
         let change =
             self.input_amount(client) - self.output_amount(client) - self.fee_amount(client);
         self.build_with_change(
@@ -295,10 +297,10 @@ impl TransactionBuilder {
         mut rng: R,
         change: Vec<Amount>,
         secp: &Secp256k1<secp256k1_zkp::All>,
-    ) -> Transaction {
+    ) -> Result<Transaction, MintClientError> {
         change_module
             .finalize_change(&mut self.tx, dbtx, change)
-            .await;
+            .await?;
 
         let txid = self.tx.tx_hash();
         if !self.keys.is_empty() {
@@ -307,7 +309,7 @@ impl TransactionBuilder {
             self.tx.signature = Some(signature);
         }
 
-        self.tx
+        Ok(self.tx)
     }
 
     fn input_amount_iter<'a, C>(
@@ -102,3 +102,28 @@ fn sanity_check_parse_fedimint_amount() {
         fedimint_core::Amount { msats: 34 * 1000 }
     );
 }
+
+#[test]
+fn sanity_check_serialize_parse_ecash() {
+    use crate::modules::mint::{Nonce, Note};
+
+    let secp = secp256k1_zkp::Secp256k1::new();
+    let notes: TieredMulti<SpendableNote> = [(1, 1u8), (2, 2), (2, 3), (8, 4)]
+        .into_iter()
+        .map(|(msats, seed)| {
+            let spend_key = secp256k1_zkp::KeyPair::from_seckey_slice(&secp, &[seed; 32])
+                .expect("valid secret key");
+            let note = Note(
+                Nonce(spend_key.x_only_public_key().0),
+                tbs::Signature(tbs::Message::from_bytes(&[seed]).0),
+            );
+            (
+                fedimint_core::Amount::from_msats(msats),
+                SpendableNote { note, spend_key },
+            )
+        })
+        .collect();
+
+    let ecash = serialize_ecash(&notes);
+    assert_eq!(parse_ecash(&ecash).unwrap(), notes);
+}
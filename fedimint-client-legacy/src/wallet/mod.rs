@@ -144,11 +144,10 @@ impl WalletClient {
             .verify(&self.context.secp, &self.config.peg_in_descriptor)
             .map_err(WalletClientError::PegInProofError)?;
 
-        let amount = Amount::from_sats(peg_in_proof.tx_output().value)
-            .saturating_sub(self.config.fee_consensus.peg_in_abs);
-        if amount == Amount::ZERO {
-            return Err(WalletClientError::PegInAmountTooSmall);
-        }
+        peg_in_amount(
+            Amount::from_sats(peg_in_proof.tx_output().value),
+            self.config.fee_consensus.peg_in_abs,
+        )?;
 
         // TODO: invalidate tweak keys on finalization
 
@@ -174,6 +173,17 @@ impl WalletClient {
     }
 }
 
+/// Returns the amount a peg-in of `value` credits once `fee` is paid
+///
+/// Fails with [`WalletClientError::PegInAmountTooSmall`] unless `value` is
+/// greater than `fee`.
+pub(crate) fn peg_in_amount(value: Amount, fee: Amount) -> Result<Amount> {
+    if value <= fee {
+        return Err(WalletClientError::PegInAmountTooSmall);
+    }
+    Ok(value - fee)
+}
+
 type Result<T> = std::result::Result<T, WalletClientError>;
 
 #[derive(Error, Debug)]
@@ -189,3 +199,27 @@ pub enum WalletClientError {
     #[error("Mint API error: {0}")]
     ApiError(#[from] MemberError),
 }
+
+#[cfg(test)]
+mod tests {
+    use fedimint_core::Amount;
+
+    use super::{peg_in_amount, WalletClientError};
+
+    #[test]
+    fn peg_in_amount_rejects_dust() {
+        let fee = Amount::from_sats(1000);
+        assert_eq!(
+            peg_in_amount(Amount::from_sats(1500), fee).unwrap(),
+            Amount::from_sats(500)
+        );
+        assert!(matches!(
+            peg_in_amount(Amount::from_sats(1000), fee),
+            Err(WalletClientError::PegInAmountTooSmall)
+        ));
+        assert!(matches!(
+            peg_in_amount(Amount::from_sats(999), fee),
+            Err(WalletClientError::PegInAmountTooSmall)
+        ));
+    }
+}
@@ -66,6 +66,10 @@ impl<T> Tiered<T> {
         self.0.insert(amt, v)
     }
 
+    pub fn remove(&mut self, amt: Amount) -> Option<T> {
+        self.0.remove(&amt)
+    }
+
     pub fn get_mut_or_default(&mut self, amt: Amount) -> &mut T
     where
         T: Default,
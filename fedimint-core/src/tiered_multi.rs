@@ -26,13 +26,24 @@ impl<T> TieredMulti<T> {
     }
 
     /// Returns the total value of all notes in msat as `Amount`
+    ///
+    /// Panics instead of wrapping around if the total overflows, which can
+    /// only happen for notes that weren't checked with
+    /// [`Self::checked_total_amount`].
     pub fn total_amount(&self) -> Amount {
-        let milli_sat = self
-            .0
+        self.checked_total_amount()
+            .expect("Total amount of the notes overflows")
+    }
+
+    /// Like [`Self::total_amount`] but returns `None` instead of overflowing,
+    /// e.g. for notes received from untrusted sources
+    pub fn checked_total_amount(&self) -> Option<Amount> {
+        self.0
             .iter()
-            .map(|(tier, notes)| tier.msats * (notes.len() as u64))
-            .sum();
-        Amount::from_msats(milli_sat)
+            .try_fold(Amount::ZERO, |total, (tier, notes)| {
+                let tier_total = tier.msats.checked_mul(notes.len() as u64)?;
+                Some(Amount::from_msats(total.msats.checked_add(tier_total)?))
+            })
     }
 
     /// Returns the number of items in all vectors
@@ -149,6 +160,35 @@ impl<T> TieredMulti<T> {
         }
     }
 
+    /// Merges the items of `other` into `self`, appending them after the items
+    /// of the same tier
+    pub fn merge(mut self, other: TieredMulti<T>) -> Self {
+        for (amount, mut items) in other.0 {
+            self.0.entry(amount).or_default().append(&mut items);
+        }
+        self
+    }
+
+    /// Removes items adding up to exactly `amount`, preferring the biggest
+    /// tiers, and returns them
+    ///
+    /// Returns `None` and leaves `self` untouched if no such items are found.
+    /// Finding them whenever possible is only guaranteed if every tier divides
+    /// the next bigger one, like powers of two do.
+    pub fn take_amount(&mut self, amount: Amount) -> Option<TieredMulti<T>> {
+        let take_counts = self.summary().take_amount(amount)?;
+
+        let mut taken = TieredMulti::default();
+        for (tier, take) in take_counts.iter() {
+            let items = self.0.get_mut(&tier).expect("tier is in the summary");
+            taken.0.insert(tier, items.split_off(items.len() - take));
+            if items.is_empty() {
+                self.0.remove(&tier);
+            }
+        }
+        Some(taken)
+    }
+
     /// Returns an `Option` with a reference to the vector of the given `Amount`
     pub fn get(&self, amt: Amount) -> Option<&Vec<T>> {
         self.0.get(&amt)
@@ -291,6 +331,21 @@ impl TieredSummary {
         tiers: &Tiered<K>,
         denomination_sets: u16,
     ) -> TieredSummary {
+        Self::try_represent_amount(amount, current_denominations, tiers, denomination_sets)
+            .unwrap_or_else(|remainder| {
+                panic!("Tiers can't represent {amount}, {remainder} are left over")
+            })
+    }
+
+    /// Like [`Self::represent_amount`] but returns the remainder that can't be
+    /// represented if `tiers` can't add up to `amount` exactly, e.g. because the
+    /// smallest tier is bigger than 1 msat
+    pub fn try_represent_amount<K>(
+        amount: Amount,
+        current_denominations: &TieredSummary,
+        tiers: &Tiered<K>,
+        denomination_sets: u16,
+    ) -> Result<TieredSummary, Amount> {
         let mut remaining_amount = amount;
         let mut denominations = TieredSummary::default();
 
@@ -316,13 +371,48 @@ impl TieredSummary {
             denominations.inc(*tier, res as usize);
         }
 
+        if remaining_amount != Amount::ZERO {
+            return Err(remaining_amount);
+        }
+
         let represented: u64 = denominations
             .0
             .iter()
             .map(|(k, v)| k.msats * (*v as u64))
             .sum();
         assert_eq!(represented, amount.msats);
-        denominations
+        Ok(denominations)
+    }
+
+    /// Removes notes adding up to exactly `amount`, preferring the biggest
+    /// tiers, and returns how many were removed from each tier
+    ///
+    /// Returns `None` and leaves `self` untouched if no such notes are found.
+    /// Finding them whenever possible is only guaranteed if every tier divides
+    /// the next bigger one, like powers of two do.
+    pub fn take_amount(&mut self, amount: Amount) -> Option<TieredSummary> {
+        let mut remaining_amount = amount;
+        let mut taken = TieredSummary::default();
+        for (tier, notes) in self.0.as_map().iter().rev() {
+            let take = min(remaining_amount / *tier, *notes as u64);
+            if take > 0 {
+                remaining_amount -= *tier * take;
+                taken.inc(*tier, take as usize);
+            }
+        }
+
+        if remaining_amount != Amount::ZERO {
+            return None;
+        }
+
+        for (tier, take) in taken.iter() {
+            let notes = self.0.get_mut(tier).expect("tier was seen above");
+            *notes -= take;
+            if *notes == 0 {
+                self.0.remove(tier);
+            }
+        }
+        Some(taken)
     }
 
     pub fn inc(&mut self, tier: Amount, n: usize) {
@@ -333,8 +423,21 @@ impl TieredSummary {
         self.0.iter().map(|(k, v)| (k, *v))
     }
 
+    /// Panics instead of wrapping around if the total overflows, see
+    /// [`Self::checked_total_amount`]
     pub fn total_amount(&self) -> Amount {
-        self.0.iter().map(|(k, v)| k * (*v as u64)).sum::<Amount>()
+        self.checked_total_amount()
+            .expect("Total amount of the summary overflows")
+    }
+
+    /// Like [`Self::total_amount`] but returns `None` instead of overflowing
+    pub fn checked_total_amount(&self) -> Option<Amount> {
+        self.0
+            .iter()
+            .try_fold(Amount::ZERO, |total, (tier, count)| {
+                let tier_total = tier.msats.checked_mul(*count as u64)?;
+                Some(Amount::from_msats(total.msats.checked_add(tier_total)?))
+            })
     }
 
     pub fn count_items(&self) -> usize {
@@ -379,6 +482,47 @@ mod test {
         assert_eq!(summary.count_tiers(), notes.count_tiers());
     }
 
+    #[test]
+    fn total_amount_sums_all_tiers() {
+        let notes = notes(vec![
+            (Amount::from_sats(1), 3),
+            (Amount::from_sats(8), 1),
+            (Amount::from_sats(64), 2),
+        ]);
+        assert_eq!(notes.total_amount(), Amount::from_sats(3 + 8 + 128));
+        assert_eq!(notes.summary().total_amount(), notes.total_amount());
+
+        assert_eq!(TieredMulti::<usize>::default().total_amount(), Amount::ZERO);
+    }
+
+    #[test]
+    fn checked_total_amount_detects_overflow() {
+        let half = Amount::from_msats(u64::MAX / 2 + 1);
+
+        assert_eq!(notes(vec![(half, 1)]).checked_total_amount(), Some(half));
+        assert_eq!(notes(vec![(half, 2)]).checked_total_amount(), None);
+        assert_eq!(
+            notes(vec![
+                (Amount::from_msats(1), 1),
+                (half, 1),
+                (Amount::from_msats(u64::MAX / 2), 1)
+            ])
+            .checked_total_amount(),
+            None
+        );
+        assert_eq!(
+            notes(vec![(half, 2)]).summary().checked_total_amount(),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn total_amount_panics_on_overflow() {
+        let half = Amount::from_msats(u64::MAX / 2 + 1);
+        notes(vec![(half, 2)]).total_amount();
+    }
+
     #[test]
     fn represent_amount_targets_denomination_sets() {
         let starting = notes(vec![
@@ -412,6 +556,100 @@ mod test {
         );
     }
 
+    #[test]
+    fn represent_amount_reports_unrepresentable_remainder() {
+        let starting = TieredSummary::default();
+
+        assert_eq!(
+            TieredSummary::try_represent_amount(
+                Amount::from_sats(100),
+                &starting,
+                &tiers(vec![1, 8, 64]),
+                0
+            ),
+            Ok(denominations(vec![
+                (Amount::from_sats(1), 4),
+                (Amount::from_sats(8), 4),
+                (Amount::from_sats(64), 1)
+            ]))
+        );
+
+        // without a 1 sat tier the last 4 sats can't be represented
+        assert_eq!(
+            TieredSummary::try_represent_amount(
+                Amount::from_sats(100),
+                &starting,
+                &tiers(vec![8, 64]),
+                0
+            ),
+            Err(Amount::from_sats(4))
+        );
+    }
+
+    #[test]
+    fn merge_appends_items_of_overlapping_tiers() {
+        let first: TieredMulti<usize> = vec![(Amount::from_sats(1), 1), (Amount::from_sats(2), 2)]
+            .into_iter()
+            .collect();
+        let second: TieredMulti<usize> = vec![(Amount::from_sats(2), 3), (Amount::from_sats(4), 4)]
+            .into_iter()
+            .collect();
+
+        let merged = first.merge(second);
+        assert_eq!(merged.get(Amount::from_sats(1)), Some(&vec![1]));
+        assert_eq!(merged.get(Amount::from_sats(2)), Some(&vec![2, 3]));
+        assert_eq!(merged.get(Amount::from_sats(4)), Some(&vec![4]));
+        assert_eq!(merged.total_amount(), Amount::from_sats(9));
+    }
+
+    #[test]
+    fn take_amount_removes_exact_items() {
+        let mut notes = notes(vec![
+            (Amount::from_sats(1), 2),
+            (Amount::from_sats(4), 1),
+            (Amount::from_sats(8), 1),
+        ]);
+
+        let taken = notes.take_amount(Amount::from_sats(5)).unwrap();
+        assert_eq!(taken.total_amount(), Amount::from_sats(5));
+        assert_eq!(notes.total_amount(), Amount::from_sats(9));
+        // emptied tiers are removed
+        assert_eq!(notes.get(Amount::from_sats(4)), None);
+
+        // 3 sats can't be made from 1 and 8 sats
+        assert_eq!(notes.take_amount(Amount::from_sats(3)), None);
+        assert_eq!(notes.total_amount(), Amount::from_sats(9));
+    }
+
+    #[test]
+    fn summary_take_amount_prefers_biggest_tiers() {
+        let mut summary = denominations(vec![
+            (Amount::from_sats(1), 3),
+            (Amount::from_sats(2), 1),
+            (Amount::from_sats(4), 2),
+        ]);
+
+        assert_eq!(
+            summary.take_amount(Amount::from_sats(7)),
+            Some(denominations(vec![
+                (Amount::from_sats(1), 1),
+                (Amount::from_sats(2), 1),
+                (Amount::from_sats(4), 1)
+            ]))
+        );
+        assert_eq!(summary.total_amount(), Amount::from_sats(6));
+        // the emptied tier is dropped rather than kept with a count of zero
+        assert_eq!(
+            summary,
+            denominations(vec![(Amount::from_sats(1), 2), (Amount::from_sats(4), 1)])
+        );
+        assert_eq!(summary.count_tiers(), 2);
+
+        // only 6 sats are left
+        assert_eq!(summary.take_amount(Amount::from_sats(7)), None);
+        assert_eq!(summary.total_amount(), Amount::from_sats(6));
+    }
+
     fn notes(notes: Vec<(Amount, usize)>) -> TieredMulti<usize> {
         notes
             .into_iter()
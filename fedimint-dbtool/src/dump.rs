@@ -378,6 +378,14 @@ impl<'a> DatabaseDump<'a> {
                         mint_client.insert("NotesPerDenomination".to_string(), Box::new(notes));
                     }
                 }
+                ClientMintRange::DbKeyPrefix::MaxNotesPerIssuance => {
+                    let notes = dbtx
+                        .get_value(&ClientMintRange::MaxNotesPerIssuanceKey)
+                        .await;
+                    if let Some(notes) = notes {
+                        mint_client.insert("MaxNotesPerIssuance".to_string(), Box::new(notes));
+                    }
+                }
             }
         }
 
@@ -441,7 +441,7 @@ impl GatewayActor {
 
         self.client
             .save_outgoing_payment(contract_account.clone())
-            .await;
+            .await?;
 
         let is_internal_payment = payment_params.maybe_internal
             && self
@@ -581,7 +581,7 @@ impl GatewayActor {
 
     pub async fn get_deposit_address(&self) -> Result<Address> {
         let rng = rand::rngs::OsRng;
-        Ok(self.client.get_new_pegin_address(rng).await)
+        Ok(self.client.get_new_pegin_address(rng).await?)
     }
 
     pub async fn deposit(
@@ -134,7 +134,7 @@ impl IGatewayClientBuilder for StandardGatewayClientBuilder {
         )?;
         let ctx = secp256k1::Secp256k1::new();
 
-        Ok(Client::new(config, decoders, module_gens, db, ctx).await)
+        Ok(Client::new(config, decoders, module_gens, db, ctx).await?)
     }
 
     async fn create_config(
@@ -63,7 +63,7 @@ impl IGatewayClientBuilder for TestGatewayClientBuilder {
             api,
             Default::default(),
         )
-        .await)
+        .await?)
     }
 
     async fn create_config(
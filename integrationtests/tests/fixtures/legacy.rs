@@ -64,14 +64,17 @@ impl<T: AsRef<ClientConfig> + Clone + Send> LegacyTestUser<T> {
         )
         .into();
 
-        let client = Arc::new(block_on(Client::new_with_api(
-            config.clone(),
-            decoders,
-            module_gens,
-            db,
-            api,
-            Default::default(),
-        )));
+        let client = Arc::new(
+            block_on(Client::new_with_api(
+                config.clone(),
+                decoders,
+                module_gens,
+                db,
+                api,
+                Default::default(),
+            ))
+            .expect("Failed to create client"),
+        );
         LegacyTestUser { client, config }
     }
 }
@@ -90,7 +93,10 @@ impl IGatewayClient for LegacyTestUser<GatewayClientConfig> {
     }
 
     async fn save_outgoing_payment(&self, contract: OutgoingContractAccount) {
-        self.client.save_outgoing_payment(contract).await
+        self.client
+            .save_outgoing_payment(contract)
+            .await
+            .expect("Failed to save outgoing payment")
     }
 
     async fn abort_outgoing_payment(&self, id: ContractId) -> LegacyClientResult<()> {
@@ -126,7 +132,10 @@ fn other<T: StdError + Send + Sync + 'static>(err: T) -> ILegacyClientError {
 #[async_trait]
 impl ILegacyWalletClient for LegacyTestUser<UserClientConfig> {
     async fn get_new_peg_in_address(&self) -> Address {
-        self.client.get_new_pegin_address(rng()).await
+        self.client
+            .get_new_pegin_address(rng())
+            .await
+            .expect("Failed to derive peg-in address")
     }
 
     async fn submit_peg_in(
@@ -184,7 +193,16 @@ impl ILegacyMintClient for LegacyTestUser<UserClientConfig> {
         self.client
             .mint_client()
             .set_notes_per_denomination(notes)
-            .await;
+            .await
+            .expect("Failed to set notes per denomination");
+    }
+
+    async fn set_max_notes_per_issuance(&self, notes: u64) {
+        self.client
+            .mint_client()
+            .set_max_notes_per_issuance(notes)
+            .await
+            .expect("Failed to set max notes per issuance");
     }
 
     async fn submit_pay_for_ecash(
@@ -201,7 +219,17 @@ impl ILegacyMintClient for LegacyTestUser<UserClientConfig> {
         &self,
         amount: Amount,
     ) -> (TieredMulti<BlindNonce>, Box<dyn Fn(OutPoint)>) {
-        self.client.receive_notes(amount).await
+        let (notes, store_issuance) = self
+            .client
+            .receive_notes(amount)
+            .await
+            .expect("Failed to create ecash");
+        (
+            notes,
+            Box::new(move |outpoint| {
+                store_issuance(outpoint).expect("Failed to store issuance");
+            }),
+        )
     }
 
     async fn get_stored_ecash(
@@ -220,7 +248,25 @@ impl ILegacyMintClient for LegacyTestUser<UserClientConfig> {
     }
 
     async fn remove_stored_ecash(&self, ecash: TieredMulti<SpendableNote>) {
-        self.client.remove_ecash(ecash).await
+        self.client
+            .remove_ecash(ecash)
+            .await
+            .expect("Failed to remove stored ecash");
+    }
+
+    async fn try_remove_stored_ecash(
+        &self,
+        ecash: TieredMulti<SpendableNote>,
+    ) -> LegacyClientResult<()> {
+        self.client
+            .remove_ecash(ecash)
+            .await
+            .map(|_summary| ())
+            .map_err(other)
+    }
+
+    async fn receive_ecash(&self, ecash: TieredMulti<SpendableNote>) -> LegacyClientResult<Amount> {
+        self.client.receive_ecash(ecash).await.map_err(other)
     }
 
     async fn remove_all_stored_ecash(&self) -> LegacyClientResult<()> {
@@ -231,12 +277,25 @@ impl ILegacyMintClient for LegacyTestUser<UserClientConfig> {
             .map_err(ILegacyClientError::Other)
     }
 
+    async fn list_active_issuances(&self) -> Vec<OutPoint> {
+        self.client
+            .list_active_issuances()
+            .await
+            .into_iter()
+            .map(|(outpoint, _issuance)| outpoint)
+            .collect()
+    }
+
     async fn await_all_issued(&self) -> LegacyClientResult<Vec<OutPoint>> {
         self.client.fetch_all_notes().await.map_err(other)
     }
 
     async fn await_ecash_issued(&self, outpoint: OutPoint) -> LegacyClientResult<()> {
-        self.client.fetch_notes(outpoint).await.map_err(other)
+        self.client
+            .fetch_notes(outpoint)
+            .await
+            .map(|_notes| ())
+            .map_err(other)
     }
 
     async fn reissue_ecash_failed_tx(&self) -> LegacyClientResult<OutPoint> {
@@ -380,6 +439,7 @@ impl ILegacyTestClient for LegacyTestUser<UserClientConfig> {
             vec![output],
             &fixtures::secp(),
         ))
+        .expect("Failed to build transaction")
         .into_type_erased()
     }
 
@@ -430,6 +490,7 @@ impl ILegacyTestClient for LegacyTestUser<UserClientConfig> {
             vec![],
             &fixtures::secp(),
         ))
+        .expect("Failed to build transaction")
         .into_type_erased()
     }
 }
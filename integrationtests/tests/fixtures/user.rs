@@ -7,6 +7,7 @@ use fedimint_client_legacy::ln::incoming::ConfirmedInvoice;
 use fedimint_client_legacy::ln::outgoing::OutgoingContractAccount;
 use fedimint_client_legacy::mint::backup::Metadata;
 use fedimint_client_legacy::mint::SpendableNote;
+use fedimint_client_legacy::ClientError;
 use fedimint_core::cancellable::Cancellable;
 use fedimint_core::config::ClientConfig;
 use fedimint_core::core::KeyPair;
@@ -37,6 +38,15 @@ pub enum ILegacyClientError {
     Other(anyhow::Error),
 }
 
+impl ILegacyClientError {
+    /// Returns the underlying legacy client error, if that's what it wraps
+    pub fn client_error(self) -> Option<ClientError> {
+        match self {
+            ILegacyClientError::Other(error) => error.downcast().ok(),
+        }
+    }
+}
+
 #[async_trait]
 /// Interface for the client that the LN gateway uses
 pub trait IGatewayClient {
@@ -141,6 +151,9 @@ pub trait ILegacyMintClient {
     /// set, but may be more storage and computationally expensive
     async fn set_notes_per_denomination(&self, notes: u16);
 
+    /// Sets the maximum number of notes a single issuance of ours may create
+    async fn set_max_notes_per_issuance(&self, notes: u64);
+
     /// Submits a transaction to the federation that spends our ecash in order
     /// to sign the ecash of a recipient
     async fn submit_pay_for_ecash(
@@ -178,10 +191,24 @@ pub trait ILegacyMintClient {
     /// that the ecash has been spent
     async fn remove_stored_ecash(&self, ecash: TieredMulti<SpendableNote>);
 
+    /// Like `remove_stored_ecash` but fails without removing anything if some
+    /// of the ecash isn't stored
+    async fn try_remove_stored_ecash(
+        &self,
+        ecash: TieredMulti<SpendableNote>,
+    ) -> LegacyClientResult<()>;
+
+    /// Stores ecash handed over to us without reissuing it, storing none of it
+    /// if some is invalid or already stored
+    async fn receive_ecash(&self, ecash: TieredMulti<SpendableNote>) -> LegacyClientResult<Amount>;
+
     /// Removes ALL ecash from our database, only really useful if we have
     /// already backed-up our ecash to the federation
     async fn remove_all_stored_ecash(&self) -> LegacyClientResult<()>;
 
+    /// Lists the out points of the ecash issuances we haven't fetched yet
+    async fn list_active_issuances(&self) -> Vec<OutPoint>;
+
     /// Waits for all of our blind ecash submitted to the federation to be
     /// issued (blind-signed)
     async fn await_all_issued(&self) -> LegacyClientResult<Vec<OutPoint>>;
@@ -23,9 +23,11 @@ use anyhow::Result;
 use assert_matches::assert_matches;
 use bitcoin::{Amount, KeyPair};
 use fedimint_client_legacy::mint::backup::Metadata;
+use fedimint_client_legacy::mint::{MintClientError, DEFAULT_MAX_NOTES_PER_ISSUANCE};
+use fedimint_client_legacy::ClientError;
 use fedimint_core::api::{GlobalFederationApi, WsFederationApi};
 use fedimint_core::outcome::TransactionStatus;
-use fedimint_core::task::TaskGroup;
+use fedimint_core::task::{timeout, TaskGroup};
 use fedimint_core::{msats, sats, TieredMulti};
 use fedimint_ln_client::contracts::{Preimage, PreimageDecryptionShare};
 use fedimint_ln_client::LightningConsensusItem;
@@ -335,6 +337,20 @@ async fn ecash_in_wallet_can_sent_through_a_tx() -> Result<()> {
         fed.spend_ecash(&*user_send, msats(1)).await;
         assert_eq!(user_send.ecash_amounts(), vec![msats(1)]);
 
+        // verify we refuse to create more notes than allowed in one issuance
+        user_receive.set_max_notes_per_issuance(1).await;
+        let notes = user_receive.all_stored_ecash().await;
+        let error = user_receive.reissue(notes).await.unwrap_err();
+        assert_matches!(
+            error.client_error(),
+            Some(ClientError::MintClientError(MintClientError::TooManyNotes(
+                ..
+            )))
+        );
+        user_receive
+            .set_max_notes_per_issuance(DEFAULT_MAX_NOTES_PER_ISSUANCE)
+            .await;
+
         // verify error occurs if we issue too many of one denomination
         user_receive.set_notes_per_denomination(10).await;
         let notes = user_receive.all_stored_ecash().await;
@@ -347,6 +363,110 @@ async fn ecash_in_wallet_can_sent_through_a_tx() -> Result<()> {
     .await
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn ecash_already_stored_cannot_be_received_again() -> Result<()> {
+    non_lightning_test(2, |fed, user, bitcoin| async move {
+        fed.mine_and_mint(&*user, &*bitcoin, sats(5000)).await;
+        let spent = fed.spend_ecash(&*user, sats(2000)).await;
+        assert_eq!(user.ecash_total(), sats(3000));
+
+        // the spent notes aren't stored anymore, but this one still is
+        let stored = user.all_stored_ecash().await;
+        let (amount, note) = stored.iter_items().next().expect("has notes");
+        let ecash = spent.merge(vec![(amount, *note)].into_iter().collect());
+
+        let error = user.receive_ecash(ecash).await.unwrap_err();
+        assert_matches!(
+            error.client_error(),
+            Some(ClientError::DuplicateNote(nonce)) if nonce == note.note.0
+        );
+        // none of the spent notes were stored either
+        assert_eq!(user.ecash_total(), sats(3000));
+    })
+    .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ecash_not_stored_cannot_be_removed() -> Result<()> {
+    non_lightning_test(2, |fed, user, bitcoin| async move {
+        fed.mine_and_mint(&*user, &*bitcoin, sats(5000)).await;
+        let spent = fed.spend_ecash(&*user, sats(2000)).await;
+
+        let stored = user.all_stored_ecash().await;
+        let error = user
+            .try_remove_stored_ecash(stored.merge(spent))
+            .await
+            .unwrap_err();
+        assert_matches!(error.client_error(), Some(ClientError::UnknownNote(_)));
+        // none of the stored notes were removed
+        assert_eq!(user.ecash_total(), sats(3000));
+    })
+    .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ecash_with_invalid_signature_cannot_be_received() -> Result<()> {
+    non_lightning_test(2, |fed, user, bitcoin| async move {
+        let receiver = user.new_client_with_peers(peers(&[0]));
+        fed.mine_and_mint(&*user, &*bitcoin, sats(5000)).await;
+
+        // a note isn't signed with the key of the next bigger tier
+        let stored = user.all_stored_ecash().await;
+        let (amount, note) = stored.iter_items().next().expect("has notes");
+        let forged = vec![(amount * 2, *note)].into_iter().collect();
+
+        let error = receiver.receive_ecash(forged).await.unwrap_err();
+        assert_matches!(
+            error.client_error(),
+            Some(ClientError::InvalidSignature(tier)) if tier == amount * 2
+        );
+        assert_eq!(receiver.ecash_total(), sats(0));
+    })
+    .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ecash_overflowing_our_balance_cannot_be_received() -> Result<()> {
+    non_lightning_test(2, |fed, user, bitcoin| async move {
+        fed.mine_and_mint(&*user, &*bitcoin, sats(5000)).await;
+
+        // on its own the note's amount fits, together with our balance it doesn't
+        let stored = user.all_stored_ecash().await;
+        let (_amount, note) = stored.iter_items().next().expect("has notes");
+        let huge = vec![(msats(u64::MAX), *note)].into_iter().collect();
+
+        let error = user.receive_ecash(huge).await.unwrap_err();
+        assert_matches!(error.client_error(), Some(ClientError::AmountOverflow));
+        assert_eq!(user.ecash_total(), sats(5000));
+    })
+    .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ecash_fetching_can_be_cancelled() -> Result<()> {
+    non_lightning_test(2, |fed, user, bitcoin| async move {
+        fed.mine_spendable_utxo(&*user, &*bitcoin, Amount::from_sat(3000))
+            .await;
+        fed.database_add_notes_for_user(&*user, sats(1000)).await;
+        fed.run_consensus_epochs(1).await; // sign the first notes
+        let unsigned = fed.database_add_notes_for_user(&*user, sats(2000)).await;
+
+        // fetching the unsigned notes is retried until the fetch is dropped
+        let fetch = timeout(Duration::from_secs(2), user.await_all_issued());
+        assert!(fetch.await.is_err());
+
+        // the signed notes were stored, the unsigned ones are still active
+        assert_eq!(user.all_stored_ecash().await.total_amount(), sats(1000));
+        assert_eq!(user.list_active_issuances().await, vec![unsigned]);
+
+        // nothing was lost, the dropped issuance can still be fetched
+        fed.run_consensus_epochs(1).await;
+        assert_eq!(user.ecash_total(), sats(3000));
+        assert_eq!(fed.max_balance_sheet(), 0);
+    })
+    .await
+}
+
 async fn drop_peer_3_during_epoch(fed: &FederationTest) -> Result<()> {
     // ensure that peers 1,2,3 create an epoch, so they can see peer 3's bad
     // proposal
@@ -903,7 +1023,11 @@ async fn lightning_gateway_can_abort_payment_to_return_user_funds() -> Result<()
             .get_outgoing_contract(contract_id)
             .await
             .unwrap();
-        gateway.client.save_outgoing_payment(contract).await;
+        gateway
+            .client
+            .save_outgoing_payment(contract)
+            .await
+            .unwrap();
 
         // Gateway fails to acquire preimage, so it cancels the contract so the user can
         // try another one